@@ -1,9 +1,10 @@
 use std::fmt;
+use std::str::FromStr;
 
 use crate::{coord::Coord, mgrs::Mgrs, utm::Utm};
 
 /// Coordinates in DD/MM/SS.(S) format
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy)]
 pub struct DMSBasic
 {
     /// Degrees: is not contained, all checks are done in DMS struct
@@ -15,7 +16,22 @@ pub struct DMSBasic
 }
 
 impl DMSBasic {
-    pub fn new(mut dd: i32, mut mm: u32, mut ss: f64) -> Self {
+    /// Build a new instance, rejecting minutes/seconds that are out of
+    /// range rather than wrapping them into the next unit.
+    pub fn new(dd: i32, mm: u32, ss: f64) -> Result<Self, CoordError> {
+        if mm >= 60 {
+            return Err(CoordError::MinutesOutOfRange(mm));
+        }
+        if !(0.0..60.0).contains(&ss) {
+            return Err(CoordError::SecondsOutOfRange(ss));
+        }
+
+        Ok(Self { dd, mm, ss })
+    }
+
+    /// Build a new instance, carrying any excess seconds into minutes and
+    /// any excess minutes into degrees instead of rejecting them.
+    pub fn new_unchecked(mut dd: i32, mut mm: u32, mut ss: f64) -> Self {
         // Seconds are modular of 60, any excess wil be converted to minutes
         if ss > 60.0 {
             mm += (ss / 60.0).trunc() as u32;
@@ -31,9 +47,21 @@ impl DMSBasic {
     }
 }
 
+impl PartialEq for DMSBasic {
+    fn eq(&self, other: &Self) -> bool {
+        // `ss` can carry sign as `-0.0` for a sub-one-degree negative angle
+        // (see `signed_dms_basic`), and `0.0 == -0.0` under IEEE 754, so a
+        // derived impl would treat e.g. 0°30'0"N and 0°30'0"S as equal.
+        dms_basic_is_negative(self) == dms_basic_is_negative(other)
+            && self.dd == other.dd
+            && self.mm == other.mm
+            && self.ss.abs() == other.ss.abs()
+    }
+}
+
 impl fmt::Display for DMSBasic {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}°{}'{}\"", self.dd.abs(), self.mm, self.ss)
+        write!(f, "{}°{}'{}\"", self.dd.abs(), self.mm, self.ss.abs())
     }
 }
 
@@ -47,11 +75,24 @@ pub struct DMS {
 }
 
 impl DMS {
-    /// Return a new DMS instance.
-    ///
-    /// Latitude will be modular 90.0
-    /// Longitude will be mobular 180.0
-    pub fn new(mut lat: DMSBasic, mut lon: DMSBasic) -> Self {
+    /// Return a new DMS instance, rejecting a latitude/longitude whose
+    /// degrees fall outside `[-90, 90]`/`[-180, 180]` rather than wrapping
+    /// them into the wrong hemisphere.
+    pub fn new(lat: DMSBasic, lon: DMSBasic) -> Result<Self, CoordError> {
+        if !(-90..=90).contains(&lat.dd) {
+            return Err(CoordError::DegreesOutOfRange { value: lat.dd, min: -90, max: 90 });
+        }
+
+        if !(-180..=180).contains(&lon.dd) {
+            return Err(CoordError::DegreesOutOfRange { value: lon.dd, min: -180, max: 180 });
+        }
+
+        Ok(Self { lat, lon })
+    }
+
+    /// Return a new DMS instance, wrapping an out-of-range latitude modular
+    /// 90.0 and an out-of-range longitude modular 180.0.
+    pub fn new_unchecked(mut lat: DMSBasic, mut lon: DMSBasic) -> Self {
         if lat.dd < -90 || lat.dd > 90 {
             lat.dd %= 90;
         }
@@ -64,26 +105,99 @@ impl DMS {
     }
 }
 
+/// Error returned by the checked [`DMSBasic::new`]/[`DMS::new`] constructors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CoordError {
+    /// Degrees fell outside the valid range for the axis.
+    DegreesOutOfRange { value: i32, min: i32, max: i32 },
+    /// Minutes must be contained in the interval `[0, 60)`.
+    MinutesOutOfRange(u32),
+    /// Seconds must be contained in the interval `[0.0, 60.0)`.
+    SecondsOutOfRange(f64),
+}
+
+impl fmt::Display for CoordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoordError::DegreesOutOfRange { value, min, max } => {
+                write!(f, "degrees out of range: {} (expected [{}, {}])", value, min, max)
+            }
+            CoordError::MinutesOutOfRange(mm) => write!(f, "minutes out of range: {}", mm),
+            CoordError::SecondsOutOfRange(ss) => write!(f, "seconds out of range: {}", ss),
+        }
+    }
+}
+
+impl std::error::Error for CoordError {}
+
+/// Build a [`DMSBasic`] from already-separated degree/minute/second
+/// magnitudes plus an explicit sign.
+///
+/// `DMSBasic::dd` is the only field that can carry a sign, which means a
+/// negative angle under one degree (`dd == 0`) would otherwise lose its
+/// sign entirely. To survive that case, the sign is folded into `ss`'s
+/// sign bit (as `-0.0` when `ss` itself is zero) whenever `dd` is zero;
+/// see [`dms_basic_is_negative`] for the matching read side.
+fn signed_dms_basic(negative: bool, dd: i32, mm: u32, mut ss: f64) -> DMSBasic {
+    if negative {
+        if dd != 0 {
+            return DMSBasic::new_unchecked(-dd, mm, ss);
+        }
+        ss = -ss;
+    }
+    DMSBasic::new_unchecked(dd, mm, ss)
+}
+
+/// Whether a [`DMSBasic`] built by [`signed_dms_basic`] represents a
+/// negative angle, handling the sub-one-degree case where the sign only
+/// survives in `ss`'s sign bit.
+fn dms_basic_is_negative(value: &DMSBasic) -> bool {
+    value.dd < 0 || (value.dd == 0 && value.ss.is_sign_negative())
+}
+
+/// Build a [`DMSBasic`] from a signed decimal-degree value, e.g. `-0.5`
+/// for half a degree south/west of the equator/prime meridian.
+fn decimal_degrees_to_dms_basic(degrees: f64) -> DMSBasic {
+    let negative = degrees.is_sign_negative();
+    let magnitude = degrees.abs();
+    let dd = magnitude.trunc() as i32;
+    let minutes = magnitude.fract() * 60.0;
+    let mm = minutes.trunc() as u32;
+    let ss = minutes.fract() * 60.0;
+    signed_dms_basic(negative, dd, mm, ss)
+}
+
+/// Decimal degrees represented by a signed [`DMSBasic`] angle (the
+/// inverse of [`decimal_degrees_to_dms_basic`]).
+fn dms_basic_to_degrees(value: &DMSBasic) -> f64 {
+    let magnitude = value.dd.unsigned_abs() as f64 + value.mm as f64 / 60.0 + value.ss.abs() / 3600.0;
+    if dms_basic_is_negative(value) {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
 impl fmt::Display for DMS {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.lat.dd >= 0 {
-            write!(f, "{}N, ", self.lat)?;
-        } else {
+        if dms_basic_is_negative(&self.lat) {
             write!(f, "{}S, ", self.lat)?;
-        }
-        if self.lon.dd >= 0 {
-            write!(f, "{}E", self.lon)
         } else {
+            write!(f, "{}N, ", self.lat)?;
+        }
+        if dms_basic_is_negative(&self.lon) {
             write!(f, "{}W", self.lon)
+        } else {
+            write!(f, "{}E", self.lon)
         }
     }
 }
 
 impl From<Coord> for DMS {
     fn from(coord: Coord) -> Self {
-        let lat = DMSBasic::new(0, 0, coord.lat * 3600.0);
-        let lon = DMSBasic::new(0, 0, coord.lon * 3600.0);
-        DMS::new(lat, lon)
+        let lat = decimal_degrees_to_dms_basic(coord.lat);
+        let lon = decimal_degrees_to_dms_basic(coord.lon);
+        DMS::new_unchecked(lat, lon)
     }
 }
 
@@ -101,36 +215,781 @@ impl From<Utm> for DMS {
     }
 }
 
+/// Error returned when a human-entered coordinate string cannot be parsed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseDmsError {
+    /// The string did not match any of the supported layouts.
+    InvalidFormat,
+    /// Degrees fell outside the valid range for the axis.
+    DegreesOutOfRange(i32),
+    /// Minutes must be contained in the interval `[0..60)`.
+    MinutesOutOfRange(u32),
+    /// Seconds must be contained in the interval `[0..60)`.
+    SecondsOutOfRange(f64),
+}
+
+impl fmt::Display for ParseDmsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseDmsError::InvalidFormat => write!(f, "unrecognised coordinate format"),
+            ParseDmsError::DegreesOutOfRange(dd) => write!(f, "degrees out of range: {}", dd),
+            ParseDmsError::MinutesOutOfRange(mm) => write!(f, "minutes out of range: {}", mm),
+            ParseDmsError::SecondsOutOfRange(ss) => write!(f, "seconds out of range: {}", ss),
+        }
+    }
+}
+
+impl std::error::Error for ParseDmsError {}
+
+impl From<CoordError> for ParseDmsError {
+    fn from(err: CoordError) -> Self {
+        match err {
+            CoordError::DegreesOutOfRange { value, .. } => ParseDmsError::DegreesOutOfRange(value),
+            CoordError::MinutesOutOfRange(mm) => ParseDmsError::MinutesOutOfRange(mm),
+            CoordError::SecondsOutOfRange(ss) => ParseDmsError::SecondsOutOfRange(ss),
+        }
+    }
+}
+
+/// Replace the various glyphs used for minute/second marks with a plain
+/// `'`/`"`, so the rest of the parser only has to deal with one spelling.
+fn normalize_glyphs(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            '′' | '’' => '\'',
+            '″' | '”' => '"',
+            c => c,
+        })
+        .collect()
+}
+
+/// Turn a decimal comma into a decimal point, but only when it sits between
+/// two digits, so it isn't confused with a lat/lon separator comma.
+fn normalize_decimal_commas(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let mut out = String::with_capacity(s.len());
+    for (i, &c) in chars.iter().enumerate() {
+        if c == ','
+            && i > 0
+            && chars[i - 1].is_ascii_digit()
+            && chars.get(i + 1).is_some_and(|c| c.is_ascii_digit())
+        {
+            out.push('.');
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Split a normalized coordinate string into its latitude and longitude
+/// halves.
+fn split_lat_lon(s: &str) -> Result<(&str, &str), ParseDmsError> {
+    if let Some(idx) = s.find([',', ';']) {
+        return Ok((&s[..idx], &s[idx + 1..]));
+    }
+
+    let hemisphere_at = |c: char| matches!(c.to_ascii_uppercase(), 'N' | 'S' | 'E' | 'W');
+    let letters: Vec<usize> = s
+        .char_indices()
+        .filter(|(_, c)| hemisphere_at(*c))
+        .map(|(i, _)| i)
+        .collect();
+
+    if letters.len() == 2 {
+        let first_is_prefix = s[..letters[0]].trim().is_empty();
+        let split_at = if first_is_prefix {
+            letters[1]
+        } else {
+            letters[0] + 1
+        };
+        return Ok((&s[..split_at], &s[split_at..]));
+    }
+
+    // No separator and not exactly two hemisphere letters: assume a signed
+    // "lat lon" pair, which must be exactly two whitespace tokens, one for
+    // each half.
+    let tokens: Vec<(usize, &str)> = s.split_whitespace().map(|tok| (offset_of(s, tok), tok)).collect();
+    if tokens.len() == 2 {
+        return Ok((&s[..tokens[1].0], &s[tokens[1].0..]));
+    }
+
+    Err(ParseDmsError::InvalidFormat)
+}
+
+/// Offset of a substring slice within its parent string.
+fn offset_of(haystack: &str, needle: &str) -> usize {
+    needle.as_ptr() as usize - haystack.as_ptr() as usize
+}
+
+/// Parse one half (latitude or longitude) of a coordinate string into a
+/// signed [`DMSBasic`].
+fn parse_component(s: &str) -> Result<DMSBasic, ParseDmsError> {
+    let s = s.trim();
+    let (body, sign) = match s.chars().next().map(|c| c.to_ascii_uppercase()) {
+        Some('N') | Some('E') => (s[1..].trim(), 1),
+        Some('S') | Some('W') => (s[1..].trim(), -1),
+        _ => match s.chars().last().map(|c| c.to_ascii_uppercase()) {
+            Some('N') | Some('E') => (s[..s.len() - 1].trim(), 1),
+            Some('S') | Some('W') => (s[..s.len() - 1].trim(), -1),
+            _ => (s, 1),
+        },
+    };
+
+    let body = body.trim();
+    let (body, explicit_sign) = match body.strip_prefix('-') {
+        Some(rest) => (rest, -1),
+        None => (body.strip_prefix('+').unwrap_or(body), 1),
+    };
+    let sign = sign * explicit_sign;
+
+    let (dd, mm, ss) = if let Some(deg_idx) = body.find('°') {
+        let dd: i32 = body[..deg_idx].trim().parse().map_err(|_| ParseDmsError::InvalidFormat)?;
+        let rest = body[deg_idx + '°'.len_utf8()..].trim();
+        if rest.is_empty() {
+            (dd, 0, 0.0)
+        } else if let Some(min_idx) = rest.find('\'') {
+            let mm: u32 = rest[..min_idx].trim().parse().map_err(|_| ParseDmsError::InvalidFormat)?;
+            let secs = rest[min_idx + 1..].trim().trim_end_matches('"').trim();
+            let ss: f64 = if secs.is_empty() {
+                0.0
+            } else {
+                secs.parse().map_err(|_| ParseDmsError::InvalidFormat)?
+            };
+            (dd, mm, ss)
+        } else {
+            let mm: f64 = rest.trim_end_matches('\'').parse().map_err(|_| ParseDmsError::InvalidFormat)?;
+            (dd, mm.trunc() as u32, (mm.fract()) * 60.0)
+        }
+    } else {
+        let value: f64 = body.parse().map_err(|_| ParseDmsError::InvalidFormat)?;
+        let dd = value.trunc() as i32;
+        let frac_minutes = value.fract().abs() * 60.0;
+        let mm = frac_minutes.trunc() as u32;
+        let ss = frac_minutes.fract() * 60.0;
+        (dd, mm, ss)
+    };
+
+    if mm >= 60 {
+        return Err(ParseDmsError::MinutesOutOfRange(mm));
+    }
+    if !(0.0..60.0).contains(&ss) {
+        return Err(ParseDmsError::SecondsOutOfRange(ss));
+    }
+
+    // Minutes/seconds were already range-checked above, so the carry path
+    // in `new_unchecked` is a no-op here. `dd` is parsed without a sign
+    // prefix (it was stripped into `sign` above), so it's always
+    // non-negative; `signed_dms_basic` carries the sign even when `dd` is 0.
+    Ok(signed_dms_basic(sign < 0, dd, mm, ss))
+}
+
+impl DMS {
+    /// Parse a human-entered coordinate string into a [`DMS`].
+    ///
+    /// Accepts `40° 26′ 46″ N 79° 58′ 56″ W`, hemisphere-first forms like
+    /// `N 40° 26′ 46″ W 79° 58′ 56″`, and signed decimal pairs with no
+    /// hemisphere letter at all, e.g. `40.4462 -79.9822`. Lat/lon may be
+    /// separated by a comma, a semicolon, or plain whitespace, and the
+    /// seconds field accepts either a decimal point or a decimal comma.
+    pub fn parse(input: &str) -> Result<Self, ParseDmsError> {
+        let normalized = normalize_decimal_commas(&normalize_glyphs(input));
+        let (lat_str, lon_str) = split_lat_lon(normalized.trim())?;
+        let lat = parse_component(lat_str)?;
+        let lon = parse_component(lon_str)?;
+        Ok(DMS::new(lat, lon)?)
+    }
+}
+
+impl FromStr for DMS {
+    type Err = ParseDmsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DMS::parse(s)
+    }
+}
+
+impl FromStr for Coord {
+    type Err = ParseDmsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DMS::parse(s).map(Into::into)
+    }
+}
+
+/// Error returned when an NMEA-0183 lat/lon field cannot be decoded.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseNmeaError {
+    /// The numeric field was not a valid `DDMM.mmmm` / `DDDMM.mmmm` token.
+    InvalidField,
+    /// The hemisphere character was not one of `N`/`S`/`E`/`W`.
+    InvalidDirection,
+}
+
+impl fmt::Display for ParseNmeaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseNmeaError::InvalidField => write!(f, "invalid NMEA degrees-decimal-minutes field"),
+            ParseNmeaError::InvalidDirection => write!(f, "invalid NMEA hemisphere character"),
+        }
+    }
+}
+
+impl std::error::Error for ParseNmeaError {}
+
+/// Decode a single NMEA `DDMM.mmmm` / `DDDMM.mmmm` field plus hemisphere
+/// character into signed decimal degrees.
+fn decode_nmea_field(field: &str, dir: &str) -> Result<f64, ParseNmeaError> {
+    let n: f64 = field.parse().map_err(|_| ParseNmeaError::InvalidField)?;
+    let degrees = (n / 100.0).trunc();
+    let minutes = n - degrees * 100.0;
+    let decimal_degrees = degrees + minutes / 60.0;
+
+    match dir.trim().to_ascii_uppercase().as_str() {
+        "N" | "E" => Ok(decimal_degrees),
+        "S" | "W" => Ok(-decimal_degrees),
+        _ => Err(ParseNmeaError::InvalidDirection),
+    }
+}
+
+impl Coord {
+    /// Build a [`Coord`] from the raw lat/lon fields of an NMEA-0183
+    /// `GGA`/`RMC` sentence, e.g. `("4807.038", "N", "01131.000", "E")`.
+    pub fn from_nmea(lat: &str, lat_dir: &str, lon: &str, lon_dir: &str) -> Result<Coord, ParseNmeaError> {
+        let lat = decode_nmea_field(lat, lat_dir)?;
+        let lon = decode_nmea_field(lon, lon_dir)?;
+        Ok(Coord::new(lat, lon))
+    }
+}
+
+/// WGS84 semi-major axis, in metres.
+const WGS84_A: f64 = 6_378_137.0;
+/// WGS84 flattening.
+const WGS84_F: f64 = 1.0 / 298.257_223_563;
+/// Maximum number of iterations before Vincenty's inverse formula gives up
+/// on convergence (this only happens for near-antipodal points).
+const VINCENTY_MAX_ITER: u32 = 200;
+/// Convergence threshold for the iterated `λ`, in radians.
+const VINCENTY_TOLERANCE: f64 = 1e-12;
+
+impl Coord {
+    /// Geodesic distance to `other` on the WGS84 ellipsoid, in metres,
+    /// using Vincenty's inverse formula.
+    pub fn distance_to(&self, other: &Coord) -> f64 {
+        vincenty_inverse(*self, *other).0
+    }
+
+    /// Initial bearing (forward azimuth) towards `other`, in degrees from
+    /// true north, using Vincenty's inverse formula.
+    pub fn initial_bearing_to(&self, other: &Coord) -> f64 {
+        vincenty_inverse(*self, *other).1
+    }
+
+    /// Destination point reached by travelling `distance` metres from
+    /// `self` along `bearing` degrees (from true north), using Vincenty's
+    /// direct formula on the WGS84 ellipsoid.
+    pub fn destination(&self, bearing: f64, distance: f64) -> Coord {
+        vincenty_direct(*self, bearing, distance)
+    }
+}
+
+/// Vincenty's inverse formula: returns `(distance_metres, initial_bearing_degrees)`.
+fn vincenty_inverse(from: Coord, to: Coord) -> (f64, f64) {
+    if (from.lat - to.lat).abs() < f64::EPSILON && (from.lon - to.lon).abs() < f64::EPSILON {
+        return (0.0, 0.0);
+    }
+
+    let a = WGS84_A;
+    let f = WGS84_F;
+    let b = a * (1.0 - f);
+
+    let phi1 = from.lat.to_radians();
+    let phi2 = to.lat.to_radians();
+    let l = (to.lon - from.lon).to_radians();
+
+    let u1 = ((1.0 - f) * phi1.tan()).atan();
+    let u2 = ((1.0 - f) * phi2.tan()).atan();
+    let (sin_u1, cos_u1) = u1.sin_cos();
+    let (sin_u2, cos_u2) = u2.sin_cos();
+
+    let mut lambda = l;
+    let (mut sin_sigma, mut cos_sigma, mut sigma) = (0.0, 0.0, 0.0);
+    let (mut cos_sq_alpha, mut cos2_sigma_m) = (0.0, 0.0);
+
+    for _ in 0..VINCENTY_MAX_ITER {
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+            + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+        .sqrt();
+        if sin_sigma == 0.0 {
+            return (0.0, 0.0);
+        }
+        cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+        sigma = sin_sigma.atan2(cos_sigma);
+
+        let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+        cos_sq_alpha = 1.0 - sin_alpha.powi(2);
+        cos2_sigma_m = if cos_sq_alpha.abs() < f64::EPSILON {
+            0.0
+        } else {
+            cos_sigma - 2.0 * sin_u1 * sin_u2 / cos_sq_alpha
+        };
+
+        let c = (f / 16.0) * cos_sq_alpha * (4.0 + f * (4.0 - 3.0 * cos_sq_alpha));
+        let lambda_prev = lambda;
+        lambda = l
+            + (1.0 - c)
+                * f
+                * sin_alpha
+                * (sigma
+                    + c * sin_sigma
+                        * (cos2_sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos2_sigma_m.powi(2))));
+
+        if (lambda - lambda_prev).abs() < VINCENTY_TOLERANCE {
+            break;
+        }
+    }
+
+    let u_sq = cos_sq_alpha * (a.powi(2) - b.powi(2)) / b.powi(2);
+    let big_a = 1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+    let big_b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+    let delta_sigma = big_b
+        * sin_sigma
+        * (cos2_sigma_m
+            + 0.25
+                * big_b
+                * (cos_sigma * (-1.0 + 2.0 * cos2_sigma_m.powi(2))
+                    - big_b / 6.0
+                        * cos2_sigma_m
+                        * (-3.0 + 4.0 * sin_sigma.powi(2))
+                        * (-3.0 + 4.0 * cos2_sigma_m.powi(2))));
+
+    let distance = b * big_a * (sigma - delta_sigma);
+
+    let (sin_lambda, cos_lambda) = lambda.sin_cos();
+    let bearing = (cos_u2 * sin_lambda).atan2(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda);
+    let bearing = (bearing.to_degrees() + 360.0) % 360.0;
+
+    (distance, bearing)
+}
+
+/// Vincenty's direct formula: the point reached from `from` after
+/// travelling `distance` metres along `bearing` degrees.
+fn vincenty_direct(from: Coord, bearing: f64, distance: f64) -> Coord {
+    let a = WGS84_A;
+    let f = WGS84_F;
+    let b = a * (1.0 - f);
+
+    let alpha1 = bearing.to_radians();
+    let (sin_alpha1, cos_alpha1) = alpha1.sin_cos();
+
+    let tan_u1 = (1.0 - f) * from.lat.to_radians().tan();
+    let cos_u1 = 1.0 / (1.0 + tan_u1.powi(2)).sqrt();
+    let sin_u1 = tan_u1 * cos_u1;
+
+    let sigma1 = sin_u1.atan2(cos_u1 * cos_alpha1);
+    let sin_alpha = cos_u1 * sin_alpha1;
+    let cos_sq_alpha = 1.0 - sin_alpha.powi(2);
+    let u_sq = cos_sq_alpha * (a.powi(2) - b.powi(2)) / b.powi(2);
+    let big_a = 1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+    let big_b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+
+    let mut sigma = distance / (b * big_a);
+    let mut cos2_sigma_m = 0.0;
+    for _ in 0..VINCENTY_MAX_ITER {
+        cos2_sigma_m = (2.0 * sigma1 + sigma).cos();
+        let sin_sigma = sigma.sin();
+        let cos_sigma = sigma.cos();
+        let delta_sigma = big_b
+            * sin_sigma
+            * (cos2_sigma_m
+                + 0.25
+                    * big_b
+                    * (cos_sigma * (-1.0 + 2.0 * cos2_sigma_m.powi(2))
+                        - big_b / 6.0
+                            * cos2_sigma_m
+                            * (-3.0 + 4.0 * sin_sigma.powi(2))
+                            * (-3.0 + 4.0 * cos2_sigma_m.powi(2))));
+        let sigma_prev = sigma;
+        sigma = distance / (b * big_a) + delta_sigma;
+        if (sigma - sigma_prev).abs() < VINCENTY_TOLERANCE {
+            break;
+        }
+    }
+
+    let (sin_sigma, cos_sigma) = sigma.sin_cos();
+    let phi2 = (sin_u1 * cos_sigma + cos_u1 * sin_sigma * cos_alpha1)
+        .atan2((1.0 - f) * (sin_alpha.powi(2) + (sin_u1 * sin_sigma - cos_u1 * cos_sigma * cos_alpha1).powi(2)).sqrt());
+    let lambda = (sin_sigma * sin_alpha1).atan2(cos_u1 * cos_sigma - sin_u1 * sin_sigma * cos_alpha1);
+    let c = (f / 16.0) * cos_sq_alpha * (4.0 + f * (4.0 - 3.0 * cos_sq_alpha));
+    let l = lambda
+        - (1.0 - c)
+            * f
+            * sin_alpha
+            * (sigma + c * sin_sigma * (cos2_sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos2_sigma_m.powi(2))));
+
+    let lon2 = from.lon + l.to_degrees();
+    Coord::new(phi2.to_degrees(), lon2)
+}
+
+/// Fixed denominator used to encode fractional EXIF GPS seconds, chosen to
+/// preserve sub-millisecond-of-arc precision.
+const EXIF_SECONDS_DENOMINATOR: u32 = 10_000;
+
+/// The three `(numerator, denominator)` rationals EXIF uses to encode a
+/// single GPS degrees/minutes/seconds component.
+pub type ExifGpsRationals = [(u32, u32); 3];
+
+/// The EXIF GPS IFD representation of a [`DMS`]: degrees/minutes/seconds
+/// rationals plus hemisphere reference character, for latitude then
+/// longitude, as produced by [`DMS::to_exif_gps`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExifGps {
+    /// Latitude degrees/minutes/seconds rationals.
+    pub lat: ExifGpsRationals,
+    /// Latitude hemisphere reference: `'N'` or `'S'`.
+    pub lat_ref: char,
+    /// Longitude degrees/minutes/seconds rationals.
+    pub lon: ExifGpsRationals,
+    /// Longitude hemisphere reference: `'E'` or `'W'`.
+    pub lon_ref: char,
+}
+
+impl DMS {
+    /// Convert to the EXIF GPS IFD representation: three `(num, den)`
+    /// rationals each for degrees, minutes and seconds, plus the
+    /// hemisphere reference character, for latitude then longitude.
+    pub fn to_exif_gps(&self) -> ExifGps {
+        let lat_ref = if dms_basic_is_negative(&self.lat) { 'S' } else { 'N' };
+        let lon_ref = if dms_basic_is_negative(&self.lon) { 'W' } else { 'E' };
+        ExifGps {
+            lat: dms_basic_to_exif_rationals(&self.lat),
+            lat_ref,
+            lon: dms_basic_to_exif_rationals(&self.lon),
+            lon_ref,
+        }
+    }
+
+    /// Build a [`DMS`] back from the EXIF GPS IFD representation produced
+    /// by [`DMS::to_exif_gps`].
+    pub fn from_exif_gps(exif: ExifGps) -> Self {
+        let lat_dms = exif_rationals_to_dms_basic(exif.lat);
+        let lat_dms = signed_dms_basic(matches!(exif.lat_ref, 'S' | 's'), lat_dms.dd, lat_dms.mm, lat_dms.ss);
+
+        let lon_dms = exif_rationals_to_dms_basic(exif.lon);
+        let lon_dms = signed_dms_basic(matches!(exif.lon_ref, 'W' | 'w'), lon_dms.dd, lon_dms.mm, lon_dms.ss);
+
+        DMS::new_unchecked(lat_dms, lon_dms)
+    }
+}
+
+fn dms_basic_to_exif_rationals(value: &DMSBasic) -> ExifGpsRationals {
+    [
+        (value.dd.unsigned_abs(), 1),
+        (value.mm, 1),
+        ((value.ss.abs() * EXIF_SECONDS_DENOMINATOR as f64).round() as u32, EXIF_SECONDS_DENOMINATOR),
+    ]
+}
+
+fn exif_rationals_to_dms_basic(rationals: ExifGpsRationals) -> DMSBasic {
+    let dd = rationals[0].0 / rationals[0].1.max(1);
+    let mm = rationals[1].0 / rationals[1].1.max(1);
+    let ss = rationals[2].0 as f64 / rationals[2].1.max(1) as f64;
+    DMSBasic::new_unchecked(dd as i32, mm, ss)
+}
+
+/// A DNS `LOC` resource record as defined by RFC 1876.
+///
+/// `size`, `horizontal_precision` and `vertical_precision` are all in
+/// centimetres, and `altitude` is a signed value in centimetres; the
+/// -100,000 m datum specified by the RFC is only applied on the wire.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LocRecord {
+    /// Latitude of the location, reusing [`DMSBasic`] from the DMS subsystem.
+    pub latitude: DMSBasic,
+    /// Longitude of the location, reusing [`DMSBasic`] from the DMS subsystem.
+    pub longitude: DMSBasic,
+    /// Altitude, in centimetres (e.g. `-100 * 100` for -100 m); `encode`
+    /// adds the 100,000 m datum offset for the wire field.
+    pub altitude_cm: i64,
+    /// Diameter of a sphere enclosing the described entity, in centimetres.
+    pub size_cm: f64,
+    /// Horizontal precision, in centimetres.
+    pub horizontal_precision_cm: f64,
+    /// Vertical precision, in centimetres.
+    pub vertical_precision_cm: f64,
+}
+
+/// Offset added to the equator/prime-meridian milliarcsecond value so it
+/// fits in an unsigned 32-bit wire field.
+const LOC_ANGLE_OFFSET: u32 = 1 << 31;
+/// Offset, in centimetres, added to altitude so the -100,000 m datum fits
+/// in an unsigned 32-bit wire field.
+const LOC_ALTITUDE_OFFSET_CM: i64 = 100_000 * 100;
+/// Lowest altitude, in centimetres, that fits the wire format's unsigned
+/// 32-bit field once [`LOC_ALTITUDE_OFFSET_CM`] is applied.
+const LOC_ALTITUDE_MIN_CM: i64 = -LOC_ALTITUDE_OFFSET_CM;
+/// Highest altitude, in centimetres, that fits the wire format's unsigned
+/// 32-bit field once [`LOC_ALTITUDE_OFFSET_CM`] is applied.
+const LOC_ALTITUDE_MAX_CM: i64 = u32::MAX as i64 - LOC_ALTITUDE_OFFSET_CM;
+
+/// Error returned when a [`LocRecord`] cannot be encoded to wire format.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LocError {
+    /// `altitude_cm` does not fit the wire format's unsigned 32-bit field
+    /// once offset from the -100,000 m datum.
+    AltitudeOutOfRange {
+        /// The altitude, in centimetres, that was rejected.
+        value: i64,
+        /// Lowest representable altitude, in centimetres.
+        min: i64,
+        /// Highest representable altitude, in centimetres.
+        max: i64,
+    },
+}
+
+impl fmt::Display for LocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LocError::AltitudeOutOfRange { value, min, max } => {
+                write!(f, "altitude {} cm out of range [{}..{}]", value, min, max)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LocError {}
+
+/// Encode a base-mantissa/power-of-ten byte: `value = mantissa * 10^exponent`
+/// centimetres, high nibble mantissa, low nibble exponent.
+fn encode_precision_byte(value_cm: f64) -> u8 {
+    if value_cm <= 0.0 {
+        return 0;
+    }
+    let exponent = value_cm.log10().floor().max(0.0) as u32;
+    let mantissa = (value_cm / 10f64.powi(exponent as i32)).round().clamp(1.0, 9.0) as u8;
+    (mantissa << 4) | (exponent as u8 & 0x0F)
+}
+
+fn decode_precision_byte(byte: u8) -> f64 {
+    let mantissa = (byte >> 4) as f64;
+    let exponent = (byte & 0x0F) as u32;
+    mantissa * 10f64.powi(exponent as i32)
+}
+
+/// Encode a signed decimal-degrees angle into RFC 1876's unsigned 32-bit
+/// milliarcsecond-offset wire format.
+fn encode_angle(degrees: f64) -> u32 {
+    let milliarcseconds = (degrees * 3_600_000.0).round() as i64;
+    (milliarcseconds + LOC_ANGLE_OFFSET as i64) as u32
+}
+
+fn decode_angle(wire: u32) -> f64 {
+    (wire as i64 - LOC_ANGLE_OFFSET as i64) as f64 / 3_600_000.0
+}
+
+impl LocRecord {
+    /// Encode this record into the 16-byte RFC 1876 `LOC` RDATA wire
+    /// format (version byte fixed at `0`).
+    pub fn encode(&self) -> Result<[u8; 16], LocError> {
+        if !(LOC_ALTITUDE_MIN_CM..=LOC_ALTITUDE_MAX_CM).contains(&self.altitude_cm) {
+            return Err(LocError::AltitudeOutOfRange {
+                value: self.altitude_cm,
+                min: LOC_ALTITUDE_MIN_CM,
+                max: LOC_ALTITUDE_MAX_CM,
+            });
+        }
+
+        let mut out = [0u8; 16];
+        out[0] = 0; // VERSION
+        out[1] = encode_precision_byte(self.size_cm);
+        out[2] = encode_precision_byte(self.horizontal_precision_cm);
+        out[3] = encode_precision_byte(self.vertical_precision_cm);
+
+        out[4..8].copy_from_slice(&encode_angle(dms_basic_to_degrees(&self.latitude)).to_be_bytes());
+        out[8..12].copy_from_slice(&encode_angle(dms_basic_to_degrees(&self.longitude)).to_be_bytes());
+
+        let altitude_wire = (self.altitude_cm + LOC_ALTITUDE_OFFSET_CM) as u32;
+        out[12..16].copy_from_slice(&altitude_wire.to_be_bytes());
+        Ok(out)
+    }
+
+    /// Decode a record from the 16-byte RFC 1876 `LOC` RDATA wire format.
+    pub fn decode(wire: &[u8; 16]) -> Self {
+        let size_cm = decode_precision_byte(wire[1]);
+        let horizontal_precision_cm = decode_precision_byte(wire[2]);
+        let vertical_precision_cm = decode_precision_byte(wire[3]);
+
+        let lat_wire = u32::from_be_bytes(wire[4..8].try_into().unwrap());
+        let lon_wire = u32::from_be_bytes(wire[8..12].try_into().unwrap());
+        let latitude = decimal_degrees_to_dms_basic(decode_angle(lat_wire));
+        let longitude = decimal_degrees_to_dms_basic(decode_angle(lon_wire));
+
+        let altitude_wire = u32::from_be_bytes(wire[12..16].try_into().unwrap());
+        let altitude_cm = altitude_wire as i64 - LOC_ALTITUDE_OFFSET_CM;
+
+        LocRecord {
+            latitude,
+            longitude,
+            altitude_cm,
+            size_cm,
+            horizontal_precision_cm,
+            vertical_precision_cm,
+        }
+    }
+}
+
+/// Angular unit carried by a coordinate tuple.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AngularUnit {
+    /// 360 units per circle.
+    Degrees,
+    /// 400 units per circle.
+    Gradians,
+    /// 2π units per circle.
+    Radians,
+}
+
+impl AngularUnit {
+    fn to_degrees(self, value: f64) -> f64 {
+        match self {
+            AngularUnit::Degrees => value,
+            AngularUnit::Gradians => value * 0.9,
+            AngularUnit::Radians => value.to_degrees(),
+        }
+    }
+
+    fn unit_from_degrees(self, degrees: f64) -> f64 {
+        match self {
+            AngularUnit::Degrees => degrees,
+            AngularUnit::Gradians => degrees / 0.9,
+            AngularUnit::Radians => degrees.to_radians(),
+        }
+    }
+}
+
+/// Axis order of a coordinate tuple.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AxisOrder {
+    /// `(lat, lon)`, matching the crate's internal [`Coord`] convention.
+    LatLon,
+    /// `(lon, lat)`.
+    LonLat,
+}
+
+/// Declarative description of a coordinate tuple's angular unit, axis
+/// order and sign convention, inspired by the "from/to" layout style of
+/// geodetic data-flow tools.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Layout {
+    /// Angular unit of both components of the tuple.
+    pub unit: AngularUnit,
+    /// Whether the tuple is `(lat, lon)` or `(lon, lat)`.
+    pub axis_order: AxisOrder,
+    /// `false` if the latitude-ish component grows south-ish instead of
+    /// north-ish (i.e. is already negated relative to the crate's
+    /// north-positive convention).
+    pub north_positive: bool,
+    /// `false` if the longitude-ish component grows west-ish instead of
+    /// east-ish.
+    pub east_positive: bool,
+}
+
+impl Layout {
+    /// The crate's own `(lat, lon)` decimal-degrees, north/east-positive
+    /// convention.
+    pub const INTERNAL: Layout = Layout {
+        unit: AngularUnit::Degrees,
+        axis_order: AxisOrder::LatLon,
+        north_positive: true,
+        east_positive: true,
+    };
+
+    /// Decode a tuple in this layout into `(lat, lon)` decimal degrees,
+    /// north/east positive.
+    fn to_internal(self, input: (f64, f64)) -> (f64, f64) {
+        let (first, second) = input;
+        let (lat, lon) = match self.axis_order {
+            AxisOrder::LatLon => (first, second),
+            AxisOrder::LonLat => (second, first),
+        };
+        let lat = self.unit.to_degrees(lat);
+        let lon = self.unit.to_degrees(lon);
+        let lat = if self.north_positive { lat } else { -lat };
+        let lon = if self.east_positive { lon } else { -lon };
+        (lat, lon)
+    }
+
+    /// Encode `(lat, lon)` decimal degrees, north/east positive, into a
+    /// tuple in this layout.
+    fn apply_from_internal(self, lat: f64, lon: f64) -> (f64, f64) {
+        let lat = if self.north_positive { lat } else { -lat };
+        let lon = if self.east_positive { lon } else { -lon };
+        let lat = self.unit.unit_from_degrees(lat);
+        let lon = self.unit.unit_from_degrees(lon);
+        match self.axis_order {
+            AxisOrder::LatLon => (lat, lon),
+            AxisOrder::LonLat => (lon, lat),
+        }
+    }
+}
+
+impl Coord {
+    /// Convert a coordinate tuple from one angular-unit/axis-order/sign
+    /// [`Layout`] to another, e.g. turning `lon,lat` gradians into `lat,lon`
+    /// decimal degrees.
+    pub fn adapt(input: (f64, f64), from: Layout, to: Layout) -> (f64, f64) {
+        let (lat, lon) = from.to_internal(input);
+        to.apply_from_internal(lat, lon)
+    }
+
+    /// Build a [`Coord`] from a tuple expressed in an arbitrary [`Layout`],
+    /// normalising it into the crate's internal lat,lon decimal-degrees
+    /// convention.
+    pub fn from_layout(input: (f64, f64), layout: Layout) -> Coord {
+        let (lat, lon) = layout.to_internal(input);
+        Coord::new(lat, lon)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn instance_dmsbasic() {
-        let lat = DMSBasic::new(49, 36, 27.40);
+        let lat = DMSBasic::new(49, 36, 27.40).unwrap();
         assert_eq!(lat.dd, 49);
         assert_eq!(lat.mm, 36);
         assert_eq!(lat.ss, 27.40);
 
-        let lat = DMSBasic::new(-1, 31, 57.30);
+        let lat = DMSBasic::new(-1, 31, 57.30).unwrap();
         assert_eq!(lat.dd, -1);
         assert_eq!(lat.mm, 31);
         assert_eq!(lat.ss, 57.30);
     }
 
+    #[test]
+    fn dmsbasic_eq_distinguishes_sub_one_degree_hemispheres() {
+        let north = signed_dms_basic(false, 0, 30, 0.0);
+        let south = signed_dms_basic(true, 0, 30, 0.0);
+        assert_ne!(north, south);
+        assert_eq!(north, DMSBasic::new(0, 30, 0.0).unwrap());
+    }
+
     #[test]
     fn instance_dms() {
-        let lat = DMSBasic::new(49, 36, 27.40);
-        let lon = DMSBasic::new(37, 19, 50.14);
-        let point = DMS::new(lat, lon);
+        let lat = DMSBasic::new(49, 36, 27.40).unwrap();
+        let lon = DMSBasic::new(37, 19, 50.14).unwrap();
+        let point = DMS::new(lat, lon).unwrap();
         assert_eq!(point.lat, lat);
         assert_eq!(point.lon, lon);
     }
 
     #[test]
     fn from_coord() {
-        let lat = DMSBasic::new(48, 35, 11.03);
-        let lon = DMSBasic::new(36, 31, 44.91);
+        let lat = DMSBasic::new(48, 35, 11.03).unwrap();
+        let lon = DMSBasic::new(36, 31, 44.91).unwrap();
         let base_point = Coord::new(48.5863964, 36.5291404);
         let point: DMS = base_point.into();
         assert!((point.lat.ss - lat.ss).abs() <= 0.01);
@@ -139,11 +998,319 @@ mod tests {
     
     #[test]
     fn to_coord() {
-        let lat = DMSBasic::new(-2, 23, 24.46);
-        let lon = DMSBasic::new(18, 32, 59.56);
-        let base_point = DMS::new(lat, lon);
+        let lat = DMSBasic::new(-2, 23, 24.46).unwrap();
+        let lon = DMSBasic::new(18, 32, 59.56).unwrap();
+        let base_point = DMS::new(lat, lon).unwrap();
         let point: Coord = base_point.into();
         assert!((point.lat - -2.3901266).abs() <= 0.01);
         assert!((point.lon - 18.5498764).abs() <= 0.01);
     }
+
+    #[test]
+    fn parse_suffix_hemisphere() {
+        let point = DMS::parse("40° 26′ 46″ N 79° 58′ 56″ W").unwrap();
+        assert_eq!(point.lat.dd, 40);
+        assert_eq!(point.lat.mm, 26);
+        assert_eq!(point.lon.dd, -79);
+        assert_eq!(point.lon.mm, 58);
+    }
+
+    #[test]
+    fn parse_prefix_hemisphere() {
+        let point = DMS::parse("N 40° 26′ 46″, W 79° 58′ 56″").unwrap();
+        assert_eq!(point.lat.dd, 40);
+        assert_eq!(point.lon.dd, -79);
+    }
+
+    #[test]
+    fn parse_signed_no_hemisphere() {
+        let point: DMS = "40.4462 -79.9822".parse().unwrap();
+        assert_eq!(point.lat.dd, 40);
+        assert_eq!(point.lon.dd, -79);
+    }
+
+    #[test]
+    fn parse_decimal_comma_seconds() {
+        let point = DMS::parse("40°26'46,5\" N; 79°58'56\" W").unwrap();
+        assert!((point.lat.ss - 46.5).abs() <= 0.001);
+    }
+
+    #[test]
+    fn parse_rejects_out_of_range_seconds() {
+        let err = DMS::parse("40°26'75\" N, 79°58'56\" W").unwrap_err();
+        assert_eq!(err, ParseDmsError::SecondsOutOfRange(75.0));
+    }
+
+    #[test]
+    fn parse_sub_one_degree_south_west_keeps_sign() {
+        let point = DMS::parse("0°30'0\" S, 79°58'56\" W").unwrap();
+        assert!(dms_basic_is_negative(&point.lat));
+        assert!(dms_basic_is_negative(&point.lon));
+    }
+
+    #[test]
+    fn display_sub_one_degree_south_west_keeps_sign() {
+        let point = DMS::parse("0°30'0\" S, 79°58'56\" W").unwrap();
+        assert_eq!(format!("{}", point), "0°30'0\"S, 79°58'56\"W");
+    }
+
+    #[test]
+    fn from_nmea_decodes_degrees_minutes() {
+        let point = Coord::from_nmea("4807.038", "N", "01131.000", "E").unwrap();
+        assert!((point.lat - 48.1173).abs() <= 0.0001);
+        assert!((point.lon - 11.5167).abs() <= 0.0001);
+    }
+
+    #[test]
+    fn from_nmea_negates_south_and_west() {
+        let point = Coord::from_nmea("3723.2475", "S", "12202.1639", "W").unwrap();
+        assert!(point.lat < 0.0);
+        assert!(point.lon < 0.0);
+    }
+
+    #[test]
+    fn from_nmea_rejects_bad_direction() {
+        let err = Coord::from_nmea("4807.038", "X", "01131.000", "E").unwrap_err();
+        assert_eq!(err, ParseNmeaError::InvalidDirection);
+    }
+
+    #[test]
+    fn from_nmea_feeds_into_dms() {
+        let coord = Coord::from_nmea("4807.038", "N", "01131.000", "E").unwrap();
+        let point: DMS = coord.into();
+        assert_eq!(point.lat.dd, 48);
+        assert_eq!(point.lon.dd, 11);
+    }
+
+    #[test]
+    fn distance_between_coincident_points_is_zero() {
+        let point = Coord::new(48.8584, 2.2945);
+        assert_eq!(point.distance_to(&point), 0.0);
+    }
+
+    #[test]
+    fn distance_eiffel_tower_to_big_ben() {
+        // Values cross-checked against standard Vincenty inverse references.
+        let eiffel_tower = Coord::new(48.8584, 2.2945);
+        let big_ben = Coord::new(51.5007, -0.1246);
+        let distance = eiffel_tower.distance_to(&big_ben);
+        assert!((distance - 340_895.0).abs() < 1_000.0);
+    }
+
+    #[test]
+    fn destination_round_trips_with_inverse() {
+        let start = Coord::new(48.8584, 2.2945);
+        let bearing = start.initial_bearing_to(&Coord::new(51.5007, -0.1246));
+        let distance = start.distance_to(&Coord::new(51.5007, -0.1246));
+        let end = start.destination(bearing, distance);
+        assert!((end.lat - 51.5007).abs() < 0.01);
+        assert!((end.lon - -0.1246).abs() < 0.01);
+    }
+
+    #[test]
+    fn exif_gps_round_trip() {
+        let point = DMS::new(DMSBasic::new(48, 51, 30.24).unwrap(), DMSBasic::new(2, 17, 40.2).unwrap()).unwrap();
+        let exif = point.to_exif_gps();
+        assert_eq!(exif.lat_ref, 'N');
+        assert_eq!(exif.lon_ref, 'E');
+        assert_eq!(exif.lat[0], (48, 1));
+        assert_eq!(exif.lat[1], (51, 1));
+
+        let back = DMS::from_exif_gps(exif);
+        assert_eq!(back.lat.dd, 48);
+        assert!((back.lat.ss - 30.24).abs() <= 0.001);
+    }
+
+    #[test]
+    fn exif_gps_negative_hemisphere() {
+        let point = DMS::new(DMSBasic::new(-33, 52, 4.0).unwrap(), DMSBasic::new(-151, 12, 36.0).unwrap()).unwrap();
+        let exif = point.to_exif_gps();
+        assert_eq!(exif.lat_ref, 'S');
+        assert_eq!(exif.lon_ref, 'W');
+
+        let back = DMS::from_exif_gps(exif);
+        assert_eq!(back.lat.dd, -33);
+        assert_eq!(back.lon.dd, -151);
+    }
+
+    #[test]
+    fn exif_gps_negative_hemisphere_under_one_degree() {
+        let point = DMS::new(decimal_degrees_to_dms_basic(-0.4543), decimal_degrees_to_dms_basic(-0.1276)).unwrap();
+        let exif = point.to_exif_gps();
+        assert_eq!(exif.lat_ref, 'S');
+        assert_eq!(exif.lon_ref, 'W');
+
+        let back = DMS::from_exif_gps(exif);
+        assert!(dms_basic_is_negative(&back.lat));
+        assert!(dms_basic_is_negative(&back.lon));
+    }
+
+    #[test]
+    fn loc_record_round_trip() {
+        let record = LocRecord {
+            latitude: DMSBasic::new(42, 21, 54.0).unwrap(),
+            longitude: DMSBasic::new(-71, 6, 18.0).unwrap(),
+            altitude_cm: -100 * 100,
+            size_cm: 100.0,
+            horizontal_precision_cm: 1000.0,
+            vertical_precision_cm: 1000.0,
+        };
+
+        let wire = record.encode().unwrap();
+        assert_eq!(wire.len(), 16);
+        assert_eq!(wire[0], 0);
+
+        let decoded = LocRecord::decode(&wire);
+        assert_eq!(decoded.latitude.dd, 42);
+        assert_eq!(decoded.longitude.dd, -71);
+        assert_eq!(decoded.altitude_cm, record.altitude_cm);
+    }
+
+    #[test]
+    fn loc_record_round_trip_keeps_sign_under_one_degree() {
+        let record = LocRecord {
+            latitude: decimal_degrees_to_dms_basic(-0.5),
+            longitude: decimal_degrees_to_dms_basic(10.0),
+            altitude_cm: 0,
+            size_cm: 100.0,
+            horizontal_precision_cm: 1000.0,
+            vertical_precision_cm: 1000.0,
+        };
+
+        let wire = record.encode().unwrap();
+        let decoded = LocRecord::decode(&wire);
+        assert!(dms_basic_is_negative(&decoded.latitude));
+        assert!(!dms_basic_is_negative(&decoded.longitude));
+    }
+
+    #[test]
+    fn loc_record_encode_rejects_altitude_out_of_range() {
+        let record = LocRecord {
+            latitude: DMSBasic::new(0, 0, 0.0).unwrap(),
+            longitude: DMSBasic::new(0, 0, 0.0).unwrap(),
+            altitude_cm: LOC_ALTITUDE_MIN_CM - 100,
+            size_cm: 100.0,
+            horizontal_precision_cm: 1000.0,
+            vertical_precision_cm: 1000.0,
+        };
+
+        let err = record.encode().unwrap_err();
+        assert_eq!(
+            err,
+            LocError::AltitudeOutOfRange {
+                value: LOC_ALTITUDE_MIN_CM - 100,
+                min: LOC_ALTITUDE_MIN_CM,
+                max: LOC_ALTITUDE_MAX_CM,
+            }
+        );
+    }
+
+    #[test]
+    fn loc_record_precision_byte_is_mantissa_exponent() {
+        let record = LocRecord {
+            latitude: DMSBasic::new(0, 0, 0.0).unwrap(),
+            longitude: DMSBasic::new(0, 0, 0.0).unwrap(),
+            altitude_cm: 0,
+            size_cm: 200_000.0,
+            horizontal_precision_cm: 0.0,
+            vertical_precision_cm: 0.0,
+        };
+        let wire = record.encode().unwrap();
+        assert_eq!(wire[1], 0x25); // 2 * 10^5 cm
+    }
+
+    #[test]
+    fn dmsbasic_new_rejects_out_of_range_minutes() {
+        let err = DMSBasic::new(40, 60, 0.0).unwrap_err();
+        assert_eq!(err, CoordError::MinutesOutOfRange(60));
+    }
+
+    #[test]
+    fn dmsbasic_new_rejects_out_of_range_seconds() {
+        let err = DMSBasic::new(40, 0, 60.0).unwrap_err();
+        assert_eq!(err, CoordError::SecondsOutOfRange(60.0));
+    }
+
+    #[test]
+    fn dmsbasic_new_rejects_negative_seconds() {
+        let err = DMSBasic::new(5, 10, -30.0).unwrap_err();
+        assert_eq!(err, CoordError::SecondsOutOfRange(-30.0));
+    }
+
+    #[test]
+    fn dms_new_rejects_out_of_range_degrees() {
+        let lat = DMSBasic::new(91, 0, 0.0).unwrap();
+        let lon = DMSBasic::new(0, 0, 0.0).unwrap();
+        let err = DMS::new(lat, lon).unwrap_err();
+        assert_eq!(err, CoordError::DegreesOutOfRange { value: 91, min: -90, max: 90 });
+    }
+
+    #[test]
+    fn dmsbasic_new_unchecked_still_carries_overflow() {
+        let point = DMSBasic::new_unchecked(0, 0, 3661.0);
+        assert_eq!(point.dd, 1);
+        assert_eq!(point.mm, 1);
+    }
+
+    #[test]
+    fn dms_new_unchecked_still_wraps_degrees() {
+        let lat = DMSBasic::new_unchecked(91, 0, 0.0);
+        let lon = DMSBasic::new_unchecked(0, 0, 0.0);
+        let point = DMS::new_unchecked(lat, lon);
+        assert_eq!(point.lat.dd, 1);
+    }
+
+    #[test]
+    fn adapt_lon_lat_gradians_to_internal() {
+        let lon_lat_gradians = Layout {
+            unit: AngularUnit::Gradians,
+            axis_order: AxisOrder::LonLat,
+            north_positive: true,
+            east_positive: true,
+        };
+        let (lat, lon) = Coord::adapt((2.549444, 54.287111), lon_lat_gradians, Layout::INTERNAL);
+        assert!((lat - 48.8584).abs() <= 0.0001);
+        assert!((lon - 2.2945).abs() <= 0.0001);
+    }
+
+    #[test]
+    fn adapt_south_west_positive_layout() {
+        let south_west_positive = Layout {
+            unit: AngularUnit::Degrees,
+            axis_order: AxisOrder::LatLon,
+            north_positive: false,
+            east_positive: false,
+        };
+        let (lat, lon) = Coord::adapt((48.8584, 2.2945), south_west_positive, Layout::INTERNAL);
+        assert!((lat - -48.8584).abs() <= 0.0001);
+        assert!((lon - -2.2945).abs() <= 0.0001);
+    }
+
+    #[test]
+    fn adapt_round_trips_through_radians() {
+        let radians_lat_lon = Layout {
+            unit: AngularUnit::Radians,
+            axis_order: AxisOrder::LatLon,
+            north_positive: true,
+            east_positive: true,
+        };
+        let input = (48.8584, 2.2945);
+        let radians = Coord::adapt(input, Layout::INTERNAL, radians_lat_lon);
+        let back = Coord::adapt(radians, radians_lat_lon, Layout::INTERNAL);
+        assert!((back.0 - input.0).abs() <= 1e-9);
+        assert!((back.1 - input.1).abs() <= 1e-9);
+    }
+
+    #[test]
+    fn from_layout_builds_coord() {
+        let lon_lat_gradians = Layout {
+            unit: AngularUnit::Gradians,
+            axis_order: AxisOrder::LonLat,
+            north_positive: true,
+            east_positive: true,
+        };
+        let coord = Coord::from_layout((2.549444, 54.287111), lon_lat_gradians);
+        assert!((coord.lat - 48.8584).abs() <= 0.0001);
+        assert!((coord.lon - 2.2945).abs() <= 0.0001);
+    }
 }
\ No newline at end of file